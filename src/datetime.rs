@@ -0,0 +1,22 @@
+//! Serialize and deserialize [RFC 2822] formatted timestamps as used by the
+//! `recorded_at` field of an [`HttpInteraction`](crate::HttpInteraction).
+//!
+//! [RFC 2822]: https://docs.rs/chrono/0.4.19/chrono/struct.DateTime.html#method.parse_from_rfc2822
+
+use chrono::{offset::FixedOffset, DateTime};
+use serde::{Deserialize, Deserializer, Serializer};
+
+pub(crate) fn serialize<S>(date: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&date.to_rfc2822())
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    DateTime::parse_from_rfc2822(&s).map_err(serde::de::Error::custom)
+}
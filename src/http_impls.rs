@@ -0,0 +1,213 @@
+//! Conversions between this crate's cassette types and the [`http`] crate,
+//! gated behind the `http` feature. These bridges let recorded cassettes drive
+//! real HTTP clients and servers instead of being parsed and inspected only.
+
+use std::convert::TryFrom;
+
+use crate::{Headers, Method, Request, Response, Status, Version};
+
+/// An error raised while converting to or from an [`http`] type.
+#[derive(Debug)]
+pub enum HttpConversionError {
+    /// An invalid HTTP method.
+    Method(http::method::InvalidMethod),
+    /// An invalid header name.
+    HeaderName(http::header::InvalidHeaderName),
+    /// An invalid header value.
+    HeaderValue(http::header::InvalidHeaderValue),
+    /// An invalid status code.
+    Status(http::status::InvalidStatusCode),
+    /// An unsupported HTTP version.
+    Version,
+    /// An error building the `http` request or response.
+    Http(http::Error),
+}
+
+impl std::fmt::Display for HttpConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpConversionError::Method(e) => write!(f, "invalid method: {}", e),
+            HttpConversionError::HeaderName(e) => write!(f, "invalid header name: {}", e),
+            HttpConversionError::HeaderValue(e) => write!(f, "invalid header value: {}", e),
+            HttpConversionError::Status(e) => write!(f, "invalid status code: {}", e),
+            HttpConversionError::Version => write!(f, "unsupported HTTP version"),
+            HttpConversionError::Http(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for HttpConversionError {}
+
+impl From<http::Error> for HttpConversionError {
+    fn from(e: http::Error) -> Self {
+        HttpConversionError::Http(e)
+    }
+}
+
+impl TryFrom<Method> for http::Method {
+    type Error = HttpConversionError;
+
+    fn try_from(method: Method) -> Result<Self, Self::Error> {
+        Ok(match method {
+            Method::Connect => http::Method::CONNECT,
+            Method::Delete => http::Method::DELETE,
+            Method::Get => http::Method::GET,
+            Method::Head => http::Method::HEAD,
+            Method::Options => http::Method::OPTIONS,
+            Method::Patch => http::Method::PATCH,
+            Method::Post => http::Method::POST,
+            Method::Put => http::Method::PUT,
+            Method::Trace => http::Method::TRACE,
+            Method::Other(s) => {
+                http::Method::from_bytes(s.as_bytes()).map_err(HttpConversionError::Method)?
+            }
+        })
+    }
+}
+
+impl From<http::Method> for Method {
+    fn from(method: http::Method) -> Self {
+        match method {
+            http::Method::CONNECT => Method::Connect,
+            http::Method::DELETE => Method::Delete,
+            http::Method::GET => Method::Get,
+            http::Method::HEAD => Method::Head,
+            http::Method::OPTIONS => Method::Options,
+            http::Method::PATCH => Method::Patch,
+            http::Method::POST => Method::Post,
+            http::Method::PUT => Method::Put,
+            http::Method::TRACE => Method::Trace,
+            other => Method::Other(other.as_str().to_string()),
+        }
+    }
+}
+
+impl From<Version> for http::Version {
+    fn from(version: Version) -> Self {
+        match version {
+            Version::Http0_9 => http::Version::HTTP_09,
+            Version::Http1_0 => http::Version::HTTP_10,
+            Version::Http1_1 => http::Version::HTTP_11,
+            Version::Http2_0 => http::Version::HTTP_2,
+            Version::Http3_0 => http::Version::HTTP_3,
+        }
+    }
+}
+
+impl TryFrom<http::Version> for Version {
+    type Error = HttpConversionError;
+
+    fn try_from(version: http::Version) -> Result<Self, Self::Error> {
+        Ok(match version {
+            http::Version::HTTP_09 => Version::Http0_9,
+            http::Version::HTTP_10 => Version::Http1_0,
+            http::Version::HTTP_11 => Version::Http1_1,
+            http::Version::HTTP_2 => Version::Http2_0,
+            http::Version::HTTP_3 => Version::Http3_0,
+            _ => return Err(HttpConversionError::Version),
+        })
+    }
+}
+
+impl TryFrom<Status> for http::StatusCode {
+    type Error = HttpConversionError;
+
+    fn try_from(status: Status) -> Result<Self, Self::Error> {
+        http::StatusCode::from_u16(status.code).map_err(HttpConversionError::Status)
+    }
+}
+
+impl From<http::StatusCode> for Status {
+    fn from(status: http::StatusCode) -> Self {
+        Status {
+            code: status.as_u16(),
+            message: status
+                .canonical_reason()
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
+/// Build an [`http::HeaderMap`] from the cassette [`Headers`] multimap.
+fn to_header_map(headers: &Headers) -> Result<http::HeaderMap, HttpConversionError> {
+    let mut map = http::HeaderMap::new();
+    for (name, values) in headers.iter() {
+        let name = http::header::HeaderName::from_bytes(name.as_bytes())
+            .map_err(HttpConversionError::HeaderName)?;
+        for value in values {
+            let value = http::header::HeaderValue::from_str(value)
+                .map_err(HttpConversionError::HeaderValue)?;
+            map.append(&name, value);
+        }
+    }
+    Ok(map)
+}
+
+/// Build the cassette [`Headers`] multimap from an [`http::HeaderMap`].
+fn from_header_map(map: &http::HeaderMap) -> Headers {
+    let mut headers = Headers::new();
+    for (name, value) in map.iter() {
+        headers.append(
+            name.as_str().to_string(),
+            value.to_str().unwrap_or_default().to_string(),
+        );
+    }
+    headers
+}
+
+impl TryFrom<Request> for http::Request<Vec<u8>> {
+    type Error = HttpConversionError;
+
+    fn try_from(req: Request) -> Result<Self, Self::Error> {
+        let method = http::Method::try_from(req.method)?;
+        let mut builder = http::Request::builder().method(method).uri(&req.uri);
+        if let Some(headers) = builder.headers_mut() {
+            *headers = to_header_map(&req.headers)?;
+        }
+        builder
+            .body(req.body.as_bytes().into_owned())
+            .map_err(HttpConversionError::Http)
+    }
+}
+
+impl TryFrom<Response> for http::Response<Vec<u8>> {
+    type Error = HttpConversionError;
+
+    fn try_from(res: Response) -> Result<Self, Self::Error> {
+        let mut builder = http::Response::builder().status(http::StatusCode::try_from(res.status)?);
+        if let Some(version) = res.http_version {
+            builder = builder.version(http::Version::from(version));
+        }
+        if let Some(headers) = builder.headers_mut() {
+            *headers = to_header_map(&res.headers)?;
+        }
+        builder
+            .body(res.body.as_bytes().into_owned())
+            .map_err(HttpConversionError::Http)
+    }
+}
+
+impl<B: AsRef<[u8]>> From<http::Response<B>> for Response {
+    fn from(res: http::Response<B>) -> Self {
+        let (parts, body) = res.into_parts();
+        Response {
+            body: crate::Body::from_bytes(body.as_ref()),
+            http_version: Version::try_from(parts.version).ok(),
+            status: Status::from(parts.status),
+            headers: from_header_map(&parts.headers),
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> From<http::Request<B>> for Request {
+    fn from(req: http::Request<B>) -> Self {
+        let (parts, body) = req.into_parts();
+        Request {
+            uri: parts.uri.to_string(),
+            body: crate::Body::from_bytes(body.as_ref()),
+            method: Method::from(parts.method),
+            headers: from_header_map(&parts.headers),
+        }
+    }
+}
@@ -49,19 +49,169 @@
 #![deny(missing_debug_implementations, nonstandard_style)]
 #![warn(missing_docs, unreachable_pub)]
 
+use std::borrow::Cow;
 use std::fmt;
 use std::marker::PhantomData;
 use std::{collections::HashMap, str::FromStr};
 
+use base64::engine::{general_purpose::STANDARD as BASE64, Engine as _};
 use chrono::{offset::FixedOffset, DateTime};
 use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeMap;
 use serde::{Deserialize, Deserializer, Serialize};
 use void::Void;
 
 mod datetime;
+#[cfg(feature = "http")]
+mod http_impls;
 
-/// An HTTP Headers type.
-pub type Headers = HashMap<String, Vec<String>>;
+/// An HTTP Headers multimap with case-insensitive names.
+///
+/// HTTP header names are case-insensitive, so `"Content-Type"` and
+/// `"content-type"` refer to the same header. This type compares names
+/// lowercased while preserving the original casing of the first name seen for
+/// serialization, so existing fixtures round-trip to the same map shape.
+#[derive(Debug, Clone, Default)]
+pub struct Headers {
+    inner: HashMap<String, HeaderEntry>,
+}
+
+/// The original-cased name plus the values recorded for a single header.
+#[derive(Debug, Clone)]
+struct HeaderEntry {
+    name: String,
+    values: Vec<String>,
+}
+
+impl Headers {
+    /// Create an empty `Headers` map.
+    pub fn new() -> Headers {
+        Headers {
+            inner: HashMap::new(),
+        }
+    }
+
+    /// Return the values recorded for `name`, ignoring case.
+    pub fn get(&self, name: &str) -> Option<&Vec<String>> {
+        self.inner.get(&name.to_ascii_lowercase()).map(|e| &e.values)
+    }
+
+    /// Return a mutable reference to the values recorded for `name`, ignoring
+    /// case.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Vec<String>> {
+        self.inner
+            .get_mut(&name.to_ascii_lowercase())
+            .map(|e| &mut e.values)
+    }
+
+    /// Insert `values` under `name`, replacing any values already recorded for
+    /// that header (ignoring case) and keeping the existing casing.
+    pub fn insert(&mut self, name: impl Into<String>, values: Vec<String>) -> Option<Vec<String>> {
+        let name = name.into();
+        let key = name.to_ascii_lowercase();
+        match self.inner.get_mut(&key) {
+            Some(entry) => Some(std::mem::replace(&mut entry.values, values)),
+            None => {
+                self.inner.insert(key, HeaderEntry { name, values });
+                None
+            }
+        }
+    }
+
+    /// Append a single value under `name`, creating the header if it does not
+    /// yet exist (ignoring case).
+    pub fn append(&mut self, name: impl Into<String>, value: String) {
+        let name = name.into();
+        let key = name.to_ascii_lowercase();
+        self.inner
+            .entry(key)
+            .or_insert_with(|| HeaderEntry {
+                name,
+                values: Vec::new(),
+            })
+            .values
+            .push(value);
+    }
+
+    /// Remove and return the values recorded for `name`, ignoring case.
+    pub fn remove(&mut self, name: &str) -> Option<Vec<String>> {
+        self.inner
+            .remove(&name.to_ascii_lowercase())
+            .map(|e| e.values)
+    }
+
+    /// Return `true` if a header with `name` is present, ignoring case.
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.inner.contains_key(&name.to_ascii_lowercase())
+    }
+
+    /// Iterate over the `(name, values)` pairs, yielding the original casing.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Vec<String>)> {
+        self.inner.values().map(|e| (&e.name, &e.values))
+    }
+
+    /// Iterate over the `(name, values)` pairs with mutable values.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&String, &mut Vec<String>)> {
+        self.inner.values_mut().map(|e| (&e.name, &mut e.values))
+    }
+
+    /// Return the number of distinct headers.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Return `true` if there are no headers.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+}
+
+impl PartialEq for Headers {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner.len() == other.inner.len()
+            && self
+                .inner
+                .iter()
+                .all(|(key, entry)| other.inner.get(key).is_some_and(|o| o.values == entry.values))
+    }
+}
+
+impl Serialize for Headers {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.inner.len()))?;
+        for entry in self.inner.values() {
+            map.serialize_entry(&entry.name, &entry.values)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Headers {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct HeadersVisitor;
+
+        impl<'de> Visitor<'de> for HeadersVisitor {
+            type Value = Headers;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map of header names to lists of values")
+            }
+
+            fn visit_map<M>(self, mut access: M) -> Result<Headers, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mut headers = Headers::new();
+                while let Some((name, values)) = access.next_entry::<String, Vec<String>>()? {
+                    headers.insert(name, values);
+                }
+                Ok(headers)
+            }
+        }
+
+        deserializer.deserialize_map(HeadersVisitor)
+    }
+}
 
 /// An identifier of the library which created the recording.
 ///
@@ -85,6 +235,218 @@ pub struct Cassette {
     pub recorded_with: RecorderId,
 }
 
+impl Cassette {
+    /// Find the first recorded [`HttpInteraction`] whose request matches `req`
+    /// according to every supplied [`RequestMatcher`].
+    ///
+    /// An interaction matches only if *all* `matchers` succeed; with an empty
+    /// slice every interaction trivially matches and the first is returned.
+    pub fn find_interaction(
+        &self,
+        req: &Request,
+        matchers: &[RequestMatcher],
+    ) -> Option<&HttpInteraction> {
+        self.http_interactions
+            .iter()
+            .find(|interaction| matchers.iter().all(|m| m.matches(&interaction.request, req)))
+    }
+
+    /// Load a `Cassette` from `path`, choosing the [`Format`] from the file
+    /// extension (`.json` → JSON, `.yaml`/`.yml` → YAML).
+    pub fn from_file<P: AsRef<std::path::Path>>(path: P) -> Result<Cassette, CassetteError> {
+        let path = path.as_ref();
+        Self::from_file_with(path, Format::from_path(path)?)
+    }
+
+    /// Load a `Cassette` from `path` using the given [`Format`].
+    pub fn from_file_with<P: AsRef<std::path::Path>>(
+        path: P,
+        format: Format,
+    ) -> Result<Cassette, CassetteError> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(match format {
+            Format::Json => serde_json::from_str(&contents)?,
+            Format::Yaml => serde_yaml::from_str(&contents)?,
+        })
+    }
+
+    /// Persist this `Cassette` to `path`, choosing the [`Format`] from the
+    /// file extension (`.json` → JSON, `.yaml`/`.yml` → YAML).
+    pub fn to_file<P: AsRef<std::path::Path>>(&self, path: P) -> Result<(), CassetteError> {
+        let path = path.as_ref();
+        self.to_file_with(path, Format::from_path(path)?)
+    }
+
+    /// Persist this `Cassette` to `path` using the given [`Format`].
+    pub fn to_file_with<P: AsRef<std::path::Path>>(
+        &self,
+        path: P,
+        format: Format,
+    ) -> Result<(), CassetteError> {
+        let contents = match format {
+            Format::Json => serde_json::to_string_pretty(self)?,
+            Format::Yaml => serde_yaml::to_string(self)?,
+        };
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+/// The wire format used to persist a [`Cassette`] to disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Serialize as JSON via [`serde_json`].
+    Json,
+    /// Serialize as YAML via [`serde_yaml`].
+    Yaml,
+}
+
+impl Format {
+    /// Pick a format from a file extension, matching `.json` to
+    /// [`Format::Json`] and `.yaml`/`.yml` to [`Format::Yaml`].
+    fn from_path(path: &std::path::Path) -> Result<Format, CassetteError> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Ok(Format::Json),
+            Some("yaml") | Some("yml") => Ok(Format::Yaml),
+            other => Err(CassetteError::UnknownFormat(
+                other.unwrap_or_default().to_string(),
+            )),
+        }
+    }
+}
+
+/// An error loading or storing a [`Cassette`].
+#[derive(Debug)]
+pub enum CassetteError {
+    /// An error reading or writing the cassette file.
+    Io(std::io::Error),
+    /// An error serializing or deserializing JSON.
+    Json(serde_json::Error),
+    /// An error serializing or deserializing YAML.
+    Yaml(serde_yaml::Error),
+    /// The file extension did not map to a known [`Format`].
+    UnknownFormat(String),
+}
+
+impl fmt::Display for CassetteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CassetteError::Io(e) => write!(f, "{}", e),
+            CassetteError::Json(e) => write!(f, "{}", e),
+            CassetteError::Yaml(e) => write!(f, "{}", e),
+            CassetteError::UnknownFormat(ext) => {
+                write!(f, "unknown cassette format for extension {:?}", ext)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CassetteError {}
+
+impl From<std::io::Error> for CassetteError {
+    fn from(e: std::io::Error) -> Self {
+        CassetteError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for CassetteError {
+    fn from(e: serde_json::Error) -> Self {
+        CassetteError::Json(e)
+    }
+}
+
+impl From<serde_yaml::Error> for CassetteError {
+    fn from(e: serde_yaml::Error) -> Self {
+        CassetteError::Yaml(e)
+    }
+}
+
+/// A facet of a [`Request`] to compare when locating a recorded interaction,
+/// mirroring VCR's `match_requests_on` option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RequestMatcher {
+    /// Match on the HTTP method (case-insensitive).
+    Method,
+    /// Match on the full request URI.
+    Uri,
+    /// Match on the URI path, ignoring any query string.
+    Path,
+    /// Match on the URI query, ignoring the order of the pairs.
+    Query,
+    /// Match on the URI host.
+    Host,
+    /// Match on the values of the named header keys (case-insensitive names).
+    Headers(Vec<String>),
+    /// Match on the request body bytes.
+    Body,
+}
+
+impl RequestMatcher {
+    /// Compare the given facet of a recorded request against an incoming one.
+    fn matches(&self, recorded: &Request, incoming: &Request) -> bool {
+        match self {
+            RequestMatcher::Method => recorded
+                .method
+                .as_str()
+                .eq_ignore_ascii_case(incoming.method.as_str()),
+            RequestMatcher::Uri => recorded.uri == incoming.uri,
+            RequestMatcher::Path => uri_path(&recorded.uri) == uri_path(&incoming.uri),
+            RequestMatcher::Query => sorted_query(&recorded.uri) == sorted_query(&incoming.uri),
+            RequestMatcher::Host => uri_host(&recorded.uri) == uri_host(&incoming.uri),
+            RequestMatcher::Headers(names) => names.iter().all(|name| {
+                header_values(&recorded.headers, name) == header_values(&incoming.headers, name)
+            }),
+            RequestMatcher::Body => recorded.body.as_bytes() == incoming.body.as_bytes(),
+        }
+    }
+}
+
+/// Split a URI into its `(scheme://authority, path, query)` components without
+/// pulling in a full URL parser.
+fn uri_parts(uri: &str) -> (&str, &str, &str) {
+    let (before_query, query) = match uri.split_once('?') {
+        Some((head, tail)) => (head, tail),
+        None => (uri, ""),
+    };
+    let authority_end = before_query
+        .find("://")
+        .map(|i| i + 3)
+        .unwrap_or(0);
+    let path_start = before_query[authority_end..]
+        .find('/')
+        .map(|i| authority_end + i)
+        .unwrap_or(before_query.len());
+    (&before_query[..path_start], &before_query[path_start..], query)
+}
+
+/// Return the path portion of a URI.
+fn uri_path(uri: &str) -> &str {
+    uri_parts(uri).1
+}
+
+/// Return the host (authority minus any scheme) of a URI.
+fn uri_host(uri: &str) -> &str {
+    let authority = uri_parts(uri).0;
+    authority.split_once("://").map(|(_, h)| h).unwrap_or(authority)
+}
+
+/// Return the query pairs of a URI sorted so ordering is irrelevant.
+fn sorted_query(uri: &str) -> Vec<(&str, &str)> {
+    let mut pairs: Vec<(&str, &str)> = uri_parts(uri)
+        .2
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+        .collect();
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Look up a header's values by case-insensitive name.
+fn header_values<'a>(headers: &'a Headers, name: &str) -> Option<&'a Vec<String>> {
+    headers.get(name)
+}
+
 /// A single HTTP Request/Response pair.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HttpInteraction {
@@ -120,13 +482,162 @@ pub struct Response {
     pub headers: Headers,
 }
 
+/// An error decoding a compressed [`Response`] body.
+#[cfg(feature = "decompression")]
+#[derive(Debug)]
+pub enum DecodeError {
+    /// An I/O error raised while inflating the compressed stream.
+    Io(std::io::Error),
+    /// The `Content-Encoding` was not one of `gzip`, `deflate`, `br`,
+    /// `identity`, or absent.
+    Unsupported(String),
+}
+
+#[cfg(feature = "decompression")]
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Io(e) => write!(f, "{}", e),
+            DecodeError::Unsupported(enc) => write!(f, "unsupported content-encoding {:?}", enc),
+        }
+    }
+}
+
+#[cfg(feature = "decompression")]
+impl std::error::Error for DecodeError {}
+
+#[cfg(feature = "decompression")]
+impl From<std::io::Error> for DecodeError {
+    fn from(e: std::io::Error) -> Self {
+        DecodeError::Io(e)
+    }
+}
+
+#[cfg(feature = "decompression")]
+impl Response {
+    /// Return the response body with any `Content-Encoding` compression
+    /// removed, mirroring VCR's `decode_compressed_response` option.
+    ///
+    /// `gzip`, `deflate`, and `br` streams are inflated; an `identity` or
+    /// absent encoding returns the raw body bytes unchanged.
+    pub fn decoded_body(&self) -> Result<Vec<u8>, DecodeError> {
+        use std::io::Read;
+
+        let bytes = self.body.as_bytes();
+        let encoding = header_values(&self.headers, "Content-Encoding")
+            .and_then(|values| values.first())
+            .map(|value| value.trim().to_ascii_lowercase());
+
+        Ok(match encoding.as_deref() {
+            None | Some("") | Some("identity") => bytes.into_owned(),
+            Some("gzip") => {
+                let mut out = Vec::new();
+                flate2::read::GzDecoder::new(bytes.as_ref()).read_to_end(&mut out)?;
+                out
+            }
+            Some("deflate") => {
+                // The `deflate` token is zlib-wrapped per the HTTP spec, but
+                // many servers emit raw DEFLATE — try zlib first, then fall
+                // back to a raw inflate.
+                let mut out = Vec::new();
+                if flate2::read::ZlibDecoder::new(bytes.as_ref())
+                    .read_to_end(&mut out)
+                    .is_err()
+                {
+                    out.clear();
+                    flate2::read::DeflateDecoder::new(bytes.as_ref()).read_to_end(&mut out)?;
+                }
+                out
+            }
+            Some("br") => {
+                let mut out = Vec::new();
+                brotli::Decompressor::new(bytes.as_ref(), 4096).read_to_end(&mut out)?;
+                out
+            }
+            Some(other) => return Err(DecodeError::Unsupported(other.to_string())),
+        })
+    }
+
+    /// Replace the body with its [`decoded_body`](Response::decoded_body),
+    /// drop the `Content-Encoding` header, and update `Content-Length` to the
+    /// decoded length.
+    pub fn decode_in_place(&mut self) -> Result<(), DecodeError> {
+        let decoded = self.decoded_body()?;
+        self.headers.remove("Content-Encoding");
+        let len = decoded.len();
+        self.body = Body::from_bytes(&decoded);
+        self.headers
+            .insert("Content-Length", vec![len.to_string()]);
+        Ok(())
+    }
+}
+
 /// A recorded HTTP Body.
+///
+/// Exactly one of [`string`](Body::string) and
+/// [`base64_string`](Body::base64_string) is populated: textual bodies are
+/// stored verbatim in `string`, while bodies that are not valid UTF-8 (or that
+/// were recorded with `preserve_exact_body_bytes`) are base64-encoded into
+/// `base64_string` with `encoding` set to `"base64"`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Body {
     /// The encoding of the HTTP body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub encoding: Option<String>,
-    /// The HTTP body encoded as a string.
-    pub string: String,
+    /// The HTTP body encoded as a UTF-8 string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub string: Option<String>,
+    /// The HTTP body encoded as a base64 string, used for non-UTF-8 payloads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base64_string: Option<String>,
+}
+
+impl Body {
+    /// Create a `Body` from raw bytes.
+    ///
+    /// When the bytes are valid UTF-8 they are stored verbatim in
+    /// [`string`](Body::string); otherwise they are base64-encoded into
+    /// [`base64_string`](Body::base64_string) and `encoding` is set to
+    /// `"base64"`. Use [`from_bytes_preserved`](Body::from_bytes_preserved) to
+    /// force base64 encoding regardless of the contents.
+    pub fn from_bytes(bytes: &[u8]) -> Body {
+        match std::str::from_utf8(bytes) {
+            Ok(s) => Body {
+                encoding: None,
+                string: Some(s.to_string()),
+                base64_string: None,
+            },
+            Err(_) => Self::from_bytes_preserved(bytes),
+        }
+    }
+
+    /// Create a `Body` storing the raw bytes base64-encoded.
+    ///
+    /// This mirrors VCR's `preserve_exact_body_bytes` mode and always sets
+    /// `encoding` to `"base64"`.
+    pub fn from_bytes_preserved(bytes: &[u8]) -> Body {
+        Body {
+            encoding: Some("base64".to_string()),
+            string: None,
+            base64_string: Some(BASE64.encode(bytes)),
+        }
+    }
+
+    /// Return the body as raw bytes, decoding
+    /// [`base64_string`](Body::base64_string) when it is present and otherwise
+    /// returning the string bytes.
+    pub fn as_bytes(&self) -> Cow<'_, [u8]> {
+        // Base64 payloads are signalled by the presence of `base64_string`, not
+        // by `encoding` — real `preserve_exact_body_bytes` cassettes store the
+        // Ruby string encoding (e.g. `"ASCII-8BIT"`) in `encoding`.
+        if let Some(encoded) = &self.base64_string {
+            return Cow::Owned(BASE64.decode(encoded).unwrap_or_default());
+        }
+        match &self.string {
+            Some(s) => Cow::Borrowed(s.as_bytes()),
+            None => Cow::Borrowed(&[]),
+        }
+    }
 }
 
 impl FromStr for Body {
@@ -137,7 +648,8 @@ impl FromStr for Body {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Body {
             encoding: None,
-            string: s.to_string(),
+            string: Some(s.to_string()),
+            base64_string: None,
         })
     }
 }
@@ -169,8 +681,7 @@ pub struct Request {
 ///
 /// WebDAV and custom methods can be created by passing a static string to the
 /// `Other` member.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Method {
     /// An HTTP `CONNECT` method.
     Connect,
@@ -207,11 +718,38 @@ impl Method {
             Method::Post => "POST",
             Method::Put => "PUT",
             Method::Trace => "TRACE",
-            Method::Other(s) => &s,
+            Method::Other(s) => s,
         }
     }
 }
 
+// Serialize every variant — including `Other` — as a single lowercase string so
+// custom/WebDAV methods round-trip against cassettes that store e.g.
+// `"method": "purge"`, rather than the nested map a derived `enum` serde emits.
+impl Serialize for Method {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.as_str().to_ascii_lowercase())
+    }
+}
+
+impl<'de> Deserialize<'de> for Method {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "connect" => Method::Connect,
+            "delete" => Method::Delete,
+            "get" => Method::Get,
+            "head" => Method::Head,
+            "options" => Method::Options,
+            "patch" => Method::Patch,
+            "post" => Method::Post,
+            "put" => Method::Put,
+            "trace" => Method::Trace,
+            _ => Method::Other(s),
+        })
+    }
+}
+
 /// The version of the HTTP protocol in use.
 #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 #[non_exhaustive]
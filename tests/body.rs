@@ -0,0 +1,30 @@
+use vcr_cassette::Body;
+
+#[test]
+fn from_bytes_keeps_utf8_as_string() {
+    let body = Body::from_bytes(b"hello");
+    assert_eq!(body.string.as_deref(), Some("hello"));
+    assert!(body.base64_string.is_none());
+    assert_eq!(body.as_bytes().as_ref(), b"hello");
+}
+
+#[test]
+fn from_bytes_roundtrips_non_utf8() {
+    let raw = [0xff, 0xfe, 0x00, 0x01];
+    let body = Body::from_bytes(&raw);
+    assert!(body.string.is_none());
+    assert!(body.base64_string.is_some());
+    assert_eq!(body.encoding.as_deref(), Some("base64"));
+    assert_eq!(body.as_bytes().as_ref(), &raw);
+}
+
+#[test]
+fn preserved_bytes_decode_regardless_of_encoding_label() {
+    // `preserve_exact_body_bytes` cassettes store the Ruby string encoding in
+    // `encoding` and signal base64 via the `base64_string` field, so decoding
+    // must key off that field rather than `encoding == "base64"`.
+    let raw = [0x00, 0x9f, 0x92, 0x96];
+    let mut body = Body::from_bytes_preserved(&raw);
+    body.encoding = Some("ASCII-8BIT".to_string());
+    assert_eq!(body.as_bytes().as_ref(), &raw);
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "decompression")]
+
+use std::io::Write;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use vcr_cassette::{Body, Headers, Response, Status};
+
+#[test]
+fn decode_in_place_inflates_gzip_and_fixes_headers() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(b"hello world").unwrap();
+    let gzipped = encoder.finish().unwrap();
+
+    let mut headers = Headers::new();
+    headers.insert("Content-Encoding", vec!["gzip".to_string()]);
+    headers.insert("Content-Length", vec![gzipped.len().to_string()]);
+    let mut response = Response {
+        body: Body::from_bytes(&gzipped),
+        http_version: None,
+        status: Status {
+            code: 200,
+            message: "OK".to_string(),
+        },
+        headers,
+    };
+
+    response.decode_in_place().unwrap();
+
+    assert_eq!(response.body.as_bytes().as_ref(), b"hello world");
+    assert!(!response.headers.contains_key("Content-Encoding"));
+    assert_eq!(
+        response.headers.get("Content-Length"),
+        Some(&vec!["11".to_string()])
+    );
+}
@@ -0,0 +1,37 @@
+use vcr_cassette::Headers;
+
+#[test]
+fn get_is_case_insensitive() {
+    let mut headers = Headers::new();
+    headers.insert("Content-Type", vec!["text/html".to_string()]);
+    assert_eq!(
+        headers.get("content-type"),
+        Some(&vec!["text/html".to_string()])
+    );
+    assert!(headers.contains_key("CONTENT-TYPE"));
+}
+
+#[test]
+fn append_accumulates_under_one_name() {
+    let mut headers = Headers::new();
+    headers.append("Set-Cookie", "a=1".to_string());
+    headers.append("set-cookie", "b=2".to_string());
+    assert_eq!(headers.len(), 1);
+    assert_eq!(headers.get("Set-Cookie").unwrap().len(), 2);
+}
+
+#[test]
+fn partial_eq_ignores_name_case() {
+    let mut a = Headers::new();
+    a.insert("Accept", vec!["*/*".to_string()]);
+    let mut b = Headers::new();
+    b.insert("accept", vec!["*/*".to_string()]);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn roundtrips_to_the_same_map_shape() {
+    let json = r#"{"Content-Type":["text/html"]}"#;
+    let headers: Headers = serde_json::from_str(json).unwrap();
+    assert_eq!(serde_json::to_string(&headers).unwrap(), json);
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "http")]
+
+use std::convert::TryFrom;
+
+use vcr_cassette::{Body, Headers, Method, Request, Response, Status, Version};
+
+#[test]
+fn method_other_roundtrips_through_http() {
+    let method = Method::Other("purge".to_string());
+    let http_method = http::Method::try_from(method.clone()).unwrap();
+    assert_eq!(http_method.as_str(), "purge");
+    assert_eq!(Method::from(http_method), method);
+}
+
+#[test]
+fn known_method_roundtrips_through_http() {
+    let http_method = http::Method::try_from(Method::Post).unwrap();
+    assert_eq!(http_method, http::Method::POST);
+    assert_eq!(Method::from(http_method), Method::Post);
+}
+
+#[test]
+fn invalid_method_is_rejected() {
+    assert!(http::Method::try_from(Method::Other("bad method".to_string())).is_err());
+}
+
+#[test]
+fn version_roundtrips_for_each_variant() {
+    for version in [
+        Version::Http0_9,
+        Version::Http1_0,
+        Version::Http1_1,
+        Version::Http2_0,
+        Version::Http3_0,
+    ] {
+        let http_version = http::Version::from(version);
+        assert_eq!(Version::try_from(http_version).unwrap(), version);
+    }
+}
+
+#[test]
+fn status_roundtrips_through_http() {
+    let status = Status {
+        code: 404,
+        message: "Not Found".to_string(),
+    };
+    let code = http::StatusCode::try_from(status.clone()).unwrap();
+    assert_eq!(code.as_u16(), 404);
+    assert_eq!(Status::from(code), status);
+}
+
+#[test]
+fn request_roundtrips_through_http() {
+    let mut headers = Headers::new();
+    headers.insert("accept", vec!["application/json".to_string()]);
+    let request = Request {
+        uri: "http://example.com/foo".to_string(),
+        body: Body::from_bytes(b"payload"),
+        method: Method::Post,
+        headers,
+    };
+
+    let http_request = http::Request::<Vec<u8>>::try_from(request.clone()).unwrap();
+    assert_eq!(Request::from(http_request), request);
+}
+
+#[test]
+fn response_roundtrips_through_http() {
+    let mut headers = Headers::new();
+    headers.insert("content-type", vec!["text/plain".to_string()]);
+    let response = Response {
+        body: Body::from_bytes(b"hello"),
+        http_version: Some(Version::Http1_1),
+        status: Status {
+            code: 200,
+            message: "OK".to_string(),
+        },
+        headers,
+    };
+
+    let http_response = http::Response::<Vec<u8>>::try_from(response.clone()).unwrap();
+    assert_eq!(Response::from(http_response), response);
+}
@@ -0,0 +1,82 @@
+use vcr_cassette::{Body, Cassette, Headers, Method, Request, RequestMatcher};
+
+fn cassette() -> Cassette {
+    let json = r#"{
+        "http_interactions": [
+            {
+                "request": {
+                    "uri": "http://example.com/search?x=1&y=2",
+                    "body": "payload",
+                    "method": "get",
+                    "headers": { "Accept": [ "application/json" ] }
+                },
+                "response": {
+                    "body": "A",
+                    "http_version": "1.1",
+                    "status": { "code": 200, "message": "OK" },
+                    "headers": {}
+                },
+                "recorded_at": "Tue, 01 Nov 2011 04:58:44 GMT"
+            }
+        ],
+        "recorded_with": "VCR"
+    }"#;
+    serde_json::from_str(json).unwrap()
+}
+
+fn request(uri: &str) -> Request {
+    let mut headers = Headers::new();
+    headers.insert("accept", vec!["application/json".to_string()]);
+    Request {
+        uri: uri.to_string(),
+        body: Body::from_bytes(b"payload"),
+        method: Method::Get,
+        headers,
+    }
+}
+
+#[test]
+fn query_order_is_irrelevant() {
+    let cassette = cassette();
+    let req = request("http://example.com/search?y=2&x=1");
+    assert!(cassette
+        .find_interaction(&req, &[RequestMatcher::Query])
+        .is_some());
+    // The full URI differs because the pairs are reordered.
+    assert!(cassette
+        .find_interaction(&req, &[RequestMatcher::Uri])
+        .is_none());
+}
+
+#[test]
+fn each_matcher_variant() {
+    let cassette = cassette();
+    let req = request("http://example.com/search?x=1&y=2");
+    for matcher in [
+        RequestMatcher::Method,
+        RequestMatcher::Uri,
+        RequestMatcher::Path,
+        RequestMatcher::Query,
+        RequestMatcher::Host,
+        RequestMatcher::Headers(vec!["Accept".to_string()]),
+        RequestMatcher::Body,
+    ] {
+        assert!(
+            cassette
+                .find_interaction(&req, std::slice::from_ref(&matcher))
+                .is_some(),
+            "matcher {:?} should match",
+            matcher
+        );
+    }
+}
+
+#[test]
+fn all_matchers_must_succeed() {
+    let cassette = cassette();
+    let req = request("http://example.com/other?x=1&y=2");
+    // Path differs, so the combined match fails even though the host matches.
+    assert!(cassette
+        .find_interaction(&req, &[RequestMatcher::Host, RequestMatcher::Path])
+        .is_none());
+}
@@ -0,0 +1,30 @@
+use vcr_cassette::Method;
+
+#[test]
+fn other_roundtrips_as_a_bare_string() {
+    let method = Method::Other("purge".to_string());
+    let json = serde_json::to_string(&method).unwrap();
+    assert_eq!(json, "\"purge\"");
+    let back: Method = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, method);
+}
+
+#[test]
+fn known_methods_serialize_lowercase() {
+    assert_eq!(serde_json::to_string(&Method::Get).unwrap(), "\"get\"");
+    assert_eq!(serde_json::to_string(&Method::Delete).unwrap(), "\"delete\"");
+}
+
+#[test]
+fn other_is_serialized_lowercase() {
+    // Every variant — including `Other` — is emitted as a single lowercase
+    // string, matching cassettes that store e.g. `"method": "purge"`.
+    let json = serde_json::to_string(&Method::Other("PURGE".to_string())).unwrap();
+    assert_eq!(json, "\"purge\"");
+}
+
+#[test]
+fn unknown_method_parses_into_other() {
+    let method: Method = serde_json::from_str("\"PROPFIND\"").unwrap();
+    assert_eq!(method, Method::Other("PROPFIND".to_string()));
+}
@@ -0,0 +1,72 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use vcr_cassette::{Cassette, CassetteError, Format};
+
+fn sample() -> Cassette {
+    let json = r#"{
+        "http_interactions": [
+            {
+                "request": {
+                    "uri": "http://localhost:7777/foo",
+                    "body": "",
+                    "method": "get",
+                    "headers": { "Accept-Encoding": [ "identity" ] }
+                },
+                "response": {
+                    "body": "Hello foo",
+                    "http_version": "1.1",
+                    "status": { "code": 200, "message": "OK" },
+                    "headers": { "Content-Length": [ "9" ] }
+                },
+                "recorded_at": "Tue, 01 Nov 2011 04:58:44 GMT"
+            }
+        ],
+        "recorded_with": "VCR 2.0.0"
+    }"#;
+    serde_json::from_str(json).unwrap()
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    let mut path = env::temp_dir();
+    path.push(format!("vcr_cassette_{}_{}", std::process::id(), name));
+    path
+}
+
+#[test]
+fn json_roundtrips_via_file() {
+    let cassette = sample();
+    let path = temp_path("roundtrip.json");
+    cassette.to_file(&path).unwrap();
+    let loaded = Cassette::from_file(&path).unwrap();
+    fs::remove_file(&path).ok();
+    assert_eq!(loaded, cassette);
+}
+
+#[test]
+fn yaml_roundtrips_via_file() {
+    let cassette = sample();
+    let path = temp_path("roundtrip.yaml");
+    cassette.to_file(&path).unwrap();
+    let loaded = Cassette::from_file(&path).unwrap();
+    fs::remove_file(&path).ok();
+    assert_eq!(loaded, cassette);
+}
+
+#[test]
+fn format_can_be_forced() {
+    let cassette = sample();
+    let path = temp_path("forced.cassette");
+    cassette.to_file_with(&path, Format::Yaml).unwrap();
+    let loaded = Cassette::from_file_with(&path, Format::Yaml).unwrap();
+    fs::remove_file(&path).ok();
+    assert_eq!(loaded, cassette);
+}
+
+#[test]
+fn unknown_extension_is_rejected() {
+    let path = temp_path("cassette.txt");
+    let err = sample().to_file(&path).unwrap_err();
+    assert!(matches!(err, CassetteError::UnknownFormat(_)));
+}